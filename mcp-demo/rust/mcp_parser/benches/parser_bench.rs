@@ -3,7 +3,7 @@
 //! Run with: cargo bench
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
-use mcp_parser::{parse_request_impl, parse_response_impl, is_valid_jsonrpc};
+use mcp_parser::{parse_request_borrowed, parse_request_impl, parse_response_impl, is_valid_jsonrpc};
 
 const SIMPLE_REQUEST: &str = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
 
@@ -48,6 +48,21 @@ fn bench_parse_complex_request(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_borrowed_vs_owned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("borrowed_vs_owned");
+    group.throughput(Throughput::Bytes(COMPLEX_REQUEST.len() as u64));
+
+    group.bench_function("owned", |b| {
+        b.iter(|| parse_request_impl(black_box(COMPLEX_REQUEST)))
+    });
+
+    group.bench_function("borrowed", |b| {
+        b.iter(|| parse_request_borrowed(black_box(COMPLEX_REQUEST)))
+    });
+
+    group.finish();
+}
+
 fn bench_parse_response(c: &mut Criterion) {
     let mut group = c.benchmark_group("parse_response");
     group.throughput(Throughput::Bytes(SIMPLE_RESPONSE.len() as u64));
@@ -92,6 +107,7 @@ criterion_group!(
     benches,
     bench_parse_simple_request,
     bench_parse_complex_request,
+    bench_borrowed_vs_owned,
     bench_parse_response,
     bench_validation,
     bench_batch_parsing,
@@ -32,8 +32,11 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 // -----------------------------------------------------------------------------
@@ -65,6 +68,41 @@ impl From<ParseError> for PyErr {
     }
 }
 
+/// Standard JSON-RPC 2.0 error codes.
+///
+/// The codes in `-32768..=-32000` are reserved by the spec; `-32099..=-32000`
+/// is the implementation-defined server-error range.
+pub mod error_codes {
+    /// Invalid JSON was received by the server.
+    pub const PARSE_ERROR: i32 = -32700;
+    /// The JSON sent is not a valid Request object.
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// The method does not exist or is not available.
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    /// Invalid method parameter(s).
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// Internal JSON-RPC error.
+    pub const INTERNAL_ERROR: i32 = -32603;
+    /// Lower bound of the server-reserved error range (inclusive).
+    pub const SERVER_ERROR_MIN: i32 = -32099;
+    /// Upper bound of the server-reserved error range (inclusive).
+    pub const SERVER_ERROR_MAX: i32 = -32000;
+}
+
+impl ParseError {
+    /// Map this parse failure onto the spec-compliant JSON-RPC error code,
+    /// so a failed parse can be turned directly into an error reply.
+    pub fn to_error_code(&self) -> i32 {
+        match self {
+            ParseError::InvalidJson(_) => error_codes::PARSE_ERROR,
+            ParseError::InvalidVersion(_) => error_codes::INVALID_REQUEST,
+            ParseError::MissingField(_) => error_codes::INVALID_REQUEST,
+            ParseError::InvalidFieldType(_, _) => error_codes::INVALID_PARAMS,
+            ParseError::InvalidId => error_codes::INVALID_REQUEST,
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Core Data Structures
 // -----------------------------------------------------------------------------
@@ -75,7 +113,121 @@ pub struct JsonRpcRequest {
     pub jsonrpc: String,
     pub id: RequestId,
     pub method: String,
-    pub params: Option<HashMap<String, Value>>,
+    #[serde(default, skip_serializing_if = "Params::is_none")]
+    pub params: Params,
+}
+
+/// Request/notification parameters.
+///
+/// Per the JSON-RPC 2.0 parameter-structures spec, `params` may be supplied
+/// either by-name (a JSON object) or by-position (a JSON array). Scalar and
+/// string params remain invalid.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Params {
+    /// By-name parameters (`{"a": 1}`).
+    ByName(HashMap<String, Value>),
+    /// By-position parameters (`[1, 2]`).
+    ByPosition(Vec<Value>),
+    /// No parameters.
+    #[default]
+    None,
+}
+
+impl Params {
+    /// Whether no parameters were supplied.
+    pub fn is_none(&self) -> bool {
+        matches!(self, Params::None)
+    }
+
+    /// Look up a by-name parameter, or `None` for positional/absent params.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Params::ByName(map) => map.get(name),
+            _ => None,
+        }
+    }
+
+    /// Look up a by-position parameter, or `None` for by-name/absent params.
+    pub fn get_index(&self, index: usize) -> Option<&Value> {
+        match self {
+            Params::ByPosition(values) => values.get(index),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for Params {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Params::ByName(map) => map.serialize(serializer),
+            Params::ByPosition(values) => values.serialize(serializer),
+            Params::None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Params {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        params_from_value(Some(&value)).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Convert an optional `params` JSON value into a [`Params`], rejecting scalars.
+fn params_from_value(value: Option<&Value>) -> Result<Params, ParseError> {
+    match value {
+        Some(Value::Object(map)) => Ok(Params::ByName(
+            map.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        )),
+        Some(Value::Array(arr)) => Ok(Params::ByPosition(arr.clone())),
+        Some(Value::Null) | None => Ok(Params::None),
+        Some(_) => Err(ParseError::InvalidFieldType(
+            "params",
+            "object, array, or null",
+        )),
+    }
+}
+
+/// Borrowed JSON-RPC request that avoids allocation on the hot path.
+///
+/// Where the input buffer outlives parsing — e.g. when the gateway proxies a
+/// request to an upstream MCP server — a borrowed view lets us skip cloning the
+/// `jsonrpc`/`method` strings and, crucially, keep `params` as the raw JSON
+/// bytes so they can be forwarded untouched. Call [`JsonRpcRequestRef::to_owned`]
+/// to materialize a [`JsonRpcRequest`] when ownership is required.
+#[derive(Debug, Clone)]
+pub struct JsonRpcRequestRef<'a> {
+    pub jsonrpc: Cow<'a, str>,
+    pub id: RequestId,
+    pub method: Cow<'a, str>,
+    pub params: Option<&'a RawValue>,
+}
+
+impl<'a> JsonRpcRequestRef<'a> {
+    /// Materialize an owned [`JsonRpcRequest`], deserializing the raw params.
+    pub fn to_owned(&self) -> Result<JsonRpcRequest, ParseError> {
+        let params = match self.params {
+            Some(raw) => {
+                let value: Value = serde_json::from_str(raw.get())
+                    .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+                params_from_value(Some(&value))?
+            }
+            None => Params::None,
+        };
+
+        Ok(JsonRpcRequest {
+            jsonrpc: self.jsonrpc.to_string(),
+            id: self.id.clone(),
+            method: self.method.to_string(),
+            params,
+        })
+    }
 }
 
 /// Request ID can be string, number, or null
@@ -121,42 +273,25 @@ pub fn parse_request_impl(input: &str) -> Result<JsonRpcRequest, ParseError> {
     let value: Value = serde_json::from_str(input)
         .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
 
+    parse_request_value(&value)
+}
+
+/// Validate an already-parsed JSON value as a JSON-RPC request.
+///
+/// Shared by [`parse_request_impl`] and the batch parser so a single element
+/// of a batch array can be validated without re-serializing it.
+fn parse_request_value(value: &Value) -> Result<JsonRpcRequest, ParseError> {
     let obj = value.as_object().ok_or(ParseError::InvalidJson(
         "Expected JSON object".to_string(),
     ))?;
 
-    // Validate jsonrpc version
-    let version = obj
-        .get("jsonrpc")
-        .and_then(|v| v.as_str())
-        .ok_or(ParseError::MissingField("jsonrpc"))?;
-
-    if version != "2.0" {
-        return Err(ParseError::InvalidVersion(version.to_string()));
-    }
+    validate_version(obj)?;
 
     // Parse ID (required for requests)
     let id = parse_id(obj.get("id").ok_or(ParseError::MissingField("id"))?)?;
 
-    // Parse method (required)
-    let method = obj
-        .get("method")
-        .and_then(|v| v.as_str())
-        .ok_or(ParseError::MissingField("method"))?
-        .to_string();
-
-    // Parse params (optional)
-    let params = match obj.get("params") {
-        Some(Value::Object(map)) => {
-            let mut result = HashMap::new();
-            for (k, v) in map {
-                result.insert(k.clone(), v.clone());
-            }
-            Some(result)
-        }
-        Some(Value::Null) | None => None,
-        Some(_) => return Err(ParseError::InvalidFieldType("params", "object or null")),
-    };
+    let method = parse_method(obj)?;
+    let params = parse_params(obj)?;
 
     Ok(JsonRpcRequest {
         jsonrpc: "2.0".to_string(),
@@ -166,16 +301,139 @@ pub fn parse_request_impl(input: &str) -> Result<JsonRpcRequest, ParseError> {
     })
 }
 
-/// Parse a JSON-RPC response
-pub fn parse_response_impl(input: &str) -> Result<JsonRpcResponse, ParseError> {
+/// Parse a JSON-RPC request without allocating owned copies of its strings.
+///
+/// `jsonrpc` and `method` borrow from `input` where possible (falling back to
+/// owned storage only if the JSON contains escapes), and `params` is retained
+/// as a raw JSON slice for zero-copy forwarding. Use this on the proxy hot path
+/// where the input buffer outlives the parsed request.
+pub fn parse_request_borrowed(input: &str) -> Result<JsonRpcRequestRef<'_>, ParseError> {
+    // `jsonrpc`/`method` are required, non-`Option` fields on purpose: serde_json
+    // only takes its borrowed-string fast path for a bare `Cow<'a, str>` field,
+    // never for one wrapped in `Option<..>`. Wrapping them in `Option` to detect
+    // a missing key would silently turn every borrow into an allocation, which
+    // defeats the point of this function. Missing-field detection instead rides
+    // on serde's own "missing field" error, translated below.
+    // A plain `Option<Value>` would read `"id": null` as `None`; this keeps a
+    // present `null` id as `Some(Value::Null)` so only an absent key is missing.
+    fn present_id<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<Value>, D::Error> {
+        Value::deserialize(d).map(Some)
+    }
+
+    #[derive(Deserialize)]
+    struct Raw<'a> {
+        #[serde(borrow)]
+        jsonrpc: Cow<'a, str>,
+        #[serde(default, deserialize_with = "present_id")]
+        id: Option<Value>,
+        #[serde(borrow)]
+        method: Cow<'a, str>,
+        #[serde(borrow, default)]
+        params: Option<&'a RawValue>,
+    }
+
+    let raw: Raw = serde_json::from_str(input).map_err(missing_field_or_invalid_json)?;
+
+    if raw.jsonrpc.as_ref() != "2.0" {
+        return Err(ParseError::InvalidVersion(raw.jsonrpc.into_owned()));
+    }
+
+    let id = parse_id(&raw.id.ok_or(ParseError::MissingField("id"))?)?;
+
+    Ok(JsonRpcRequestRef {
+        jsonrpc: raw.jsonrpc,
+        id,
+        method: raw.method,
+        params: raw.params,
+    })
+}
+
+/// Translate a `serde_json` deserialization error into a [`ParseError`].
+///
+/// `Raw`'s required fields are plain (non-`Option`) so serde_json can take its
+/// borrowed-string fast path; the cost is that a missing `jsonrpc`/`method` key
+/// surfaces as serde's generic "missing field" error rather than our own
+/// [`ParseError::MissingField`]. Recover the field name from that message so
+/// callers still see the same error they'd get from the owned parser.
+fn missing_field_or_invalid_json(err: serde_json::Error) -> ParseError {
+    let msg = err.to_string();
+    if let Some(field) = msg
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        match field {
+            "jsonrpc" => return ParseError::MissingField("jsonrpc"),
+            "method" => return ParseError::MissingField("method"),
+            _ => {}
+        }
+    }
+    ParseError::InvalidJson(msg)
+}
+
+/// A parsed JSON-RPC message: either a request (has `id`) or a notification.
+///
+/// Per JSON-RPC 2.0, the presence of the `id` member — even `null` —
+/// distinguishes a request (which expects a reply) from a notification (which
+/// does not). MCP relies on notifications such as `notifications/initialized`
+/// and `notifications/cancelled`, so the gateway needs to route them without
+/// emitting a response.
+#[derive(Debug, Clone)]
+pub enum Message {
+    Request(JsonRpcRequest),
+    Notification(JsonRpcNotification),
+}
+
+impl Message {
+    /// Whether this message is a notification (no `id`, no reply expected).
+    pub fn is_notification(&self) -> bool {
+        matches!(self, Message::Notification(_))
+    }
+}
+
+/// Parsed JSON-RPC notification (a method call with no `id`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Params::is_none")]
+    pub params: Params,
+}
+
+/// Parse a JSON-RPC message, distinguishing requests from notifications.
+///
+/// The `id` key is the discriminator: if present (even `null`) the message is a
+/// request, otherwise it is a notification.
+pub fn parse_message_impl(input: &str) -> Result<Message, ParseError> {
     let value: Value = serde_json::from_str(input)
         .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
 
+    parse_message_value(&value)
+}
+
+/// Validate an already-parsed JSON value as a JSON-RPC message.
+///
+/// Shared by [`parse_message_impl`] and the batch parser so a single element
+/// of a batch array can be classified as a request or notification without
+/// re-serializing it.
+fn parse_message_value(value: &Value) -> Result<Message, ParseError> {
     let obj = value.as_object().ok_or(ParseError::InvalidJson(
         "Expected JSON object".to_string(),
     ))?;
 
-    // Validate jsonrpc version
+    if obj.contains_key("id") {
+        Ok(Message::Request(parse_request_value(value)?))
+    } else {
+        validate_version(obj)?;
+        Ok(Message::Notification(JsonRpcNotification {
+            jsonrpc: "2.0".to_string(),
+            method: parse_method(obj)?,
+            params: parse_params(obj)?,
+        }))
+    }
+}
+
+/// Validate the `jsonrpc` version member, shared by requests and notifications.
+fn validate_version(obj: &serde_json::Map<String, Value>) -> Result<(), ParseError> {
     let version = obj
         .get("jsonrpc")
         .and_then(|v| v.as_str())
@@ -185,13 +443,83 @@ pub fn parse_response_impl(input: &str) -> Result<JsonRpcResponse, ParseError> {
         return Err(ParseError::InvalidVersion(version.to_string()));
     }
 
-    // Parse ID
+    Ok(())
+}
+
+/// Parse the required `method` member.
+fn parse_method(obj: &serde_json::Map<String, Value>) -> Result<String, ParseError> {
+    Ok(obj
+        .get("method")
+        .and_then(|v| v.as_str())
+        .ok_or(ParseError::MissingField("method"))?
+        .to_string())
+}
+
+/// Parse the optional `params` member (by-name object, by-position array, or null).
+fn parse_params(obj: &serde_json::Map<String, Value>) -> Result<Params, ParseError> {
+    params_from_value(obj.get("params"))
+}
+
+/// Parse a JSON-RPC response
+pub fn parse_response_impl(input: &str) -> Result<JsonRpcResponse, ParseError> {
+    let value: Value = serde_json::from_str(input)
+        .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+
+    parse_response_value(&value)
+}
+
+/// A parsed JSON-RPC response: a response contains exactly one of `result`
+/// (success) or `error` (failure).
+#[derive(Debug, Clone)]
+pub enum ResponseKind {
+    Success(JsonRpcResponse),
+    Error(JsonRpcError),
+}
+
+/// Parse a JSON-RPC error response.
+pub fn parse_error_impl(input: &str) -> Result<JsonRpcError, ParseError> {
+    let value: Value = serde_json::from_str(input)
+        .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+
+    parse_error_value(&value)
+}
+
+/// Parse a JSON-RPC response of either kind.
+///
+/// A response carries exactly one of `result` or `error`; it is an error for
+/// both or neither to be present.
+pub fn parse_response_any_impl(input: &str) -> Result<ResponseKind, ParseError> {
+    let value: Value = serde_json::from_str(input)
+        .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+
+    let obj = value.as_object().ok_or(ParseError::InvalidJson(
+        "Expected JSON object".to_string(),
+    ))?;
+
+    match (obj.contains_key("result"), obj.contains_key("error")) {
+        (true, false) => Ok(ResponseKind::Success(parse_response_value(&value)?)),
+        (false, true) => Ok(ResponseKind::Error(parse_error_value(&value)?)),
+        (true, true) => Err(ParseError::InvalidFieldType(
+            "response",
+            "exactly one of result or error",
+        )),
+        (false, false) => Err(ParseError::MissingField("result or error")),
+    }
+}
+
+/// Validate an already-parsed value as a success response.
+fn parse_response_value(value: &Value) -> Result<JsonRpcResponse, ParseError> {
+    let obj = value.as_object().ok_or(ParseError::InvalidJson(
+        "Expected JSON object".to_string(),
+    ))?;
+
+    validate_version(obj)?;
+
     let id = match obj.get("id") {
         Some(v) => parse_id(v)?,
         None => RequestId::Null,
     };
 
-    // Parse result
     let result = obj
         .get("result")
         .cloned()
@@ -204,6 +532,118 @@ pub fn parse_response_impl(input: &str) -> Result<JsonRpcResponse, ParseError> {
     })
 }
 
+/// Validate an already-parsed value as an error response.
+fn parse_error_value(value: &Value) -> Result<JsonRpcError, ParseError> {
+    let obj = value.as_object().ok_or(ParseError::InvalidJson(
+        "Expected JSON object".to_string(),
+    ))?;
+
+    validate_version(obj)?;
+
+    let id = match obj.get("id") {
+        Some(v) => parse_id(v)?,
+        None => RequestId::Null,
+    };
+
+    let error_obj = obj
+        .get("error")
+        .and_then(|v| v.as_object())
+        .ok_or(ParseError::MissingField("error"))?;
+
+    let code = error_obj
+        .get("code")
+        .and_then(|v| v.as_i64())
+        .ok_or(ParseError::InvalidFieldType("error.code", "integer"))?;
+    let code = i32::try_from(code)
+        .map_err(|_| ParseError::InvalidFieldType("error.code", "i32"))?;
+
+    let message = error_obj
+        .get("message")
+        .and_then(|v| v.as_str())
+        .ok_or(ParseError::MissingField("error.message"))?
+        .to_string();
+
+    let data = error_obj.get("data").cloned();
+
+    Ok(JsonRpcError {
+        jsonrpc: "2.0".to_string(),
+        id,
+        error: ErrorData {
+            code,
+            message,
+            data,
+        },
+    })
+}
+
+/// Build a serialized JSON-RPC error response.
+///
+/// Used by the gateway to synthesize spec-compliant error replies — for
+/// example, turning a [`ParseError`] into a reply via
+/// [`ParseError::to_error_code`].
+pub fn build_error_response(
+    id: RequestId,
+    code: i32,
+    message: &str,
+    data: Option<Value>,
+) -> String {
+    let error = JsonRpcError {
+        jsonrpc: "2.0".to_string(),
+        id,
+        error: ErrorData {
+            code,
+            message: message.to_string(),
+            data,
+        },
+    };
+    serde_json::to_string(&error).expect("JsonRpcError is always serializable")
+}
+
+/// Build a serialized JSON-RPC success response.
+pub fn build_success_response(id: RequestId, result: Value) -> String {
+    let response = JsonRpcResponse {
+        jsonrpc: "2.0".to_string(),
+        id,
+        result,
+    };
+    serde_json::to_string(&response).expect("JsonRpcResponse is always serializable")
+}
+
+/// Parse a JSON-RPC batch from a single buffer.
+///
+/// The JSON-RPC 2.0 spec allows a client to send several requests as one
+/// top-level array (`[{...},{...}]`); over a transport the gateway receives
+/// this as one buffer rather than pre-split messages. A real-world batch
+/// routinely mixes requests with notifications (e.g. `notifications/cancelled`
+/// alongside a request awaiting a reply), so each element is classified the
+/// same way [`parse_message_impl`] classifies a standalone message. This
+/// function parses the top-level value and:
+///
+/// - for an array, validates each element independently, returning a
+///   `Vec<Result<..>>` so a single malformed entry does not discard the whole
+///   batch (the gateway can reply with a partial batch of errors);
+/// - for a single object, returns a one-element batch;
+/// - for an empty array, fails with [`ParseError::InvalidJson`] (an empty batch
+///   is invalid per spec);
+/// - for any other top-level value, fails with [`ParseError::InvalidJson`].
+pub fn parse_batch_impl(input: &str) -> Result<Vec<Result<Message, ParseError>>, ParseError> {
+    let value: Value = serde_json::from_str(input)
+        .map_err(|e| ParseError::InvalidJson(e.to_string()))?;
+
+    match value {
+        Value::Array(items) => {
+            if items.is_empty() {
+                return Err(ParseError::InvalidJson("Empty batch array".to_string()));
+            }
+            Ok(items.iter().map(parse_message_value).collect())
+        }
+        Value::Object(_) => Ok(vec![parse_message_value(&value)]),
+        _ => Err(ParseError::InvalidJson(
+            "Expected array or object".to_string(),
+        )),
+    }
+}
+
 /// Parse request ID from JSON value
 fn parse_id(value: &Value) -> Result<RequestId, ParseError> {
     match value {
@@ -235,6 +675,99 @@ pub fn is_valid_jsonrpc(input: &str) -> bool {
         .unwrap_or(false)
 }
 
+// -----------------------------------------------------------------------------
+// Method Dispatch
+// -----------------------------------------------------------------------------
+
+/// A registered method handler: given the request's params, it returns either a
+/// result value (success) or [`ErrorData`] (an application error).
+pub type Handler = Arc<dyn Fn(&Params) -> Result<Value, ErrorData> + Send + Sync>;
+
+/// Routes parsed messages to handlers registered by method name.
+///
+/// This turns the parser into a minimal MCP server core: callers register a
+/// handler per method and feed in parsed messages, getting back a
+/// [`ResponseKind`] for requests and nothing for notifications. Unknown methods
+/// are answered automatically with a [`error_codes::METHOD_NOT_FOUND`] error.
+#[derive(Clone, Default)]
+pub struct Router {
+    handlers: HashMap<String, Handler>,
+}
+
+impl Router {
+    /// Create an empty router.
+    pub fn new() -> Self {
+        Router::default()
+    }
+
+    /// Register a handler for `method`, replacing any existing handler.
+    pub fn register<F>(&mut self, method: &str, handler: F)
+    where
+        F: Fn(&Params) -> Result<Value, ErrorData> + Send + Sync + 'static,
+    {
+        self.handlers.insert(method.to_string(), Arc::new(handler));
+    }
+
+    /// Dispatch a request, always producing a reply. An unknown method yields a
+    /// `MethodNotFound` error response.
+    pub fn dispatch(&self, request: JsonRpcRequest) -> ResponseKind {
+        match self.handlers.get(&request.method) {
+            Some(handler) => match handler(&request.params) {
+                Ok(result) => ResponseKind::Success(JsonRpcResponse {
+                    jsonrpc: "2.0".to_string(),
+                    id: request.id,
+                    result,
+                }),
+                Err(error) => ResponseKind::Error(JsonRpcError {
+                    jsonrpc: "2.0".to_string(),
+                    id: request.id,
+                    error,
+                }),
+            },
+            None => ResponseKind::Error(method_not_found(request.id, &request.method)),
+        }
+    }
+
+    /// Dispatch any message, returning `None` for notifications (which expect no
+    /// reply). A notification still invokes its handler if one is registered.
+    pub fn dispatch_message(&self, message: Message) -> Option<ResponseKind> {
+        match message {
+            Message::Request(request) => Some(self.dispatch(request)),
+            Message::Notification(notification) => {
+                if let Some(handler) = self.handlers.get(&notification.method) {
+                    let _ = handler(&notification.params);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Build a `MethodNotFound` error for an unregistered method.
+fn method_not_found(id: RequestId, method: &str) -> JsonRpcError {
+    JsonRpcError {
+        jsonrpc: "2.0".to_string(),
+        id,
+        error: ErrorData {
+            code: error_codes::METHOD_NOT_FOUND,
+            message: format!("Method not found: {method}"),
+            data: None,
+        },
+    }
+}
+
+/// Serialize a [`ResponseKind`] to its JSON-RPC wire form.
+fn serialize_response_kind(kind: &ResponseKind) -> String {
+    match kind {
+        ResponseKind::Success(resp) => {
+            serde_json::to_string(resp).expect("JsonRpcResponse is always serializable")
+        }
+        ResponseKind::Error(err) => {
+            serde_json::to_string(err).expect("JsonRpcError is always serializable")
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Python Bindings
 // -----------------------------------------------------------------------------
@@ -270,23 +803,10 @@ impl PyJsonRpcRequest {
         &self.inner.method
     }
 
-    /// The request parameters (if any)
+    /// The request parameters: a dict (by-name), a list (by-position), or None
     #[getter]
     fn params(&self) -> PyObject {
-        Python::with_gil(|py| {
-            match &self.inner.params {
-                Some(map) => {
-                    // Convert to Python dict
-                    let dict = pyo3::types::PyDict::new(py);
-                    for (k, v) in map {
-                        let py_value = json_value_to_py(py, v);
-                        dict.set_item(k, py_value).unwrap();
-                    }
-                    dict.into_py(py)
-                }
-                None => py.None(),
-            }
-        })
+        Python::with_gil(|py| params_to_py(py, &self.inner.params))
     }
 
     /// Convert to JSON string
@@ -341,6 +861,124 @@ impl PyJsonRpcResponse {
     }
 }
 
+/// Python wrapper for JsonRpcError
+#[pyclass(name = "JsonRpcError")]
+#[derive(Clone)]
+pub struct PyJsonRpcError {
+    inner: JsonRpcError,
+}
+
+#[pymethods]
+impl PyJsonRpcError {
+    #[getter]
+    fn jsonrpc(&self) -> &str {
+        &self.inner.jsonrpc
+    }
+
+    #[getter]
+    fn id(&self) -> PyObject {
+        Python::with_gil(|py| match &self.inner.id {
+            RequestId::String(s) => s.into_py(py),
+            RequestId::Number(n) => n.into_py(py),
+            RequestId::Null => py.None(),
+        })
+    }
+
+    /// The numeric error code
+    #[getter]
+    fn code(&self) -> i32 {
+        self.inner.error.code
+    }
+
+    /// The human-readable error message
+    #[getter]
+    fn message(&self) -> &str {
+        &self.inner.error.message
+    }
+
+    /// Optional structured error data
+    #[getter]
+    fn data(&self) -> PyObject {
+        Python::with_gil(|py| match &self.inner.error.data {
+            Some(v) => json_value_to_py(py, v),
+            None => py.None(),
+        })
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "JsonRpcError(id={:?}, code={}, message={:?})",
+            self.inner.id, self.inner.error.code, self.inner.error.message
+        )
+    }
+}
+
+/// Python wrapper for JsonRpcNotification
+#[pyclass(name = "JsonRpcNotification")]
+#[derive(Clone)]
+pub struct PyJsonRpcNotification {
+    inner: JsonRpcNotification,
+}
+
+#[pymethods]
+impl PyJsonRpcNotification {
+    #[getter]
+    fn jsonrpc(&self) -> &str {
+        &self.inner.jsonrpc
+    }
+
+    /// The method name being notified
+    #[getter]
+    fn method(&self) -> &str {
+        &self.inner.method
+    }
+
+    /// The notification parameters: a dict (by-name), a list (by-position), or None
+    #[getter]
+    fn params(&self) -> PyObject {
+        Python::with_gil(|py| params_to_py(py, &self.inner.params))
+    }
+
+    /// Notifications never carry an `id`; this is always `True`.
+    fn is_notification(&self) -> bool {
+        true
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JsonRpcNotification(method={:?})", self.inner.method)
+    }
+}
+
+/// Convert [`Params`] to the corresponding Python value: a dict for by-name,
+/// a list for by-position, or `None`.
+fn params_to_py(py: Python<'_>, params: &Params) -> PyObject {
+    match params {
+        Params::ByName(map) => {
+            let dict = pyo3::types::PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(k, json_value_to_py(py, v)).unwrap();
+            }
+            dict.into_py(py)
+        }
+        Params::ByPosition(values) => {
+            let list: Vec<PyObject> =
+                values.iter().map(|v| json_value_to_py(py, v)).collect();
+            list.into_py(py)
+        }
+        Params::None => py.None(),
+    }
+}
+
 /// Convert serde_json Value to Python object
 fn json_value_to_py(py: Python<'_>, value: &Value) -> PyObject {
     match value {
@@ -370,6 +1008,63 @@ fn json_value_to_py(py: Python<'_>, value: &Value) -> PyObject {
     }
 }
 
+/// Convert a Python object into a serde_json Value.
+///
+/// Mirrors [`json_value_to_py`] for the builder APIs, which accept arbitrary
+/// Python payloads for `result`/`data`.
+fn py_to_json_value(py: Python<'_>, obj: &PyObject) -> PyResult<Value> {
+    use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyString};
+
+    let bound = obj.bind(py);
+    if bound.is_none() {
+        Ok(Value::Null)
+    } else if let Ok(b) = bound.downcast::<PyBool>() {
+        Ok(Value::Bool(b.is_true()))
+    } else if let Ok(s) = bound.downcast::<PyString>() {
+        Ok(Value::String(s.to_str()?.to_string()))
+    } else if let Ok(list) = bound.downcast::<PyList>() {
+        let mut out = Vec::with_capacity(list.len());
+        for item in list.iter() {
+            out.push(py_to_json_value(py, &item.into_py(py))?);
+        }
+        Ok(Value::Array(out))
+    } else if let Ok(dict) = bound.downcast::<PyDict>() {
+        let mut map = serde_json::Map::new();
+        for (k, v) in dict.iter() {
+            let key: String = k.extract()?;
+            map.insert(key, py_to_json_value(py, &v.into_py(py))?);
+        }
+        Ok(Value::Object(map))
+    } else if let Ok(i) = bound.extract::<i64>() {
+        Ok(Value::Number(i.into()))
+    } else if bound.downcast::<PyFloat>().is_ok() {
+        let f: f64 = bound.extract()?;
+        serde_json::Number::from_f64(f)
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err("float is not representable in JSON"))
+    } else {
+        Err(PyValueError::new_err(
+            "unsupported type for JSON conversion",
+        ))
+    }
+}
+
+/// Convert a Python `id` value (str, int, or None) into a [`RequestId`].
+fn py_to_request_id(py: Python<'_>, obj: &PyObject) -> PyResult<RequestId> {
+    let bound = obj.bind(py);
+    if bound.is_none() {
+        Ok(RequestId::Null)
+    } else if let Ok(s) = bound.extract::<String>() {
+        Ok(RequestId::String(s))
+    } else if let Ok(n) = bound.extract::<i64>() {
+        Ok(RequestId::Number(n))
+    } else {
+        Err(PyValueError::new_err(
+            "id must be a string, integer, or None",
+        ))
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Python Module Functions
 // -----------------------------------------------------------------------------
@@ -406,6 +1101,146 @@ fn parse_requests_batch(inputs: Vec<&str>) -> PyResult<Vec<PyJsonRpcRequest>> {
         .collect()
 }
 
+/// Build a serialized JSON-RPC error response string.
+#[pyfunction]
+#[pyo3(name = "build_error_response", signature = (id, code, message, data=None))]
+fn build_error_response_py(
+    py: Python<'_>,
+    id: PyObject,
+    code: i32,
+    message: &str,
+    data: Option<PyObject>,
+) -> PyResult<String> {
+    let id = py_to_request_id(py, &id)?;
+    let data = match data {
+        Some(d) => Some(py_to_json_value(py, &d)?),
+        None => None,
+    };
+    Ok(build_error_response(id, code, message, data))
+}
+
+/// Build a serialized JSON-RPC success response string.
+#[pyfunction]
+#[pyo3(name = "build_success_response")]
+fn build_success_response_py(
+    py: Python<'_>,
+    id: PyObject,
+    result: PyObject,
+) -> PyResult<String> {
+    let id = py_to_request_id(py, &id)?;
+    let result = py_to_json_value(py, &result)?;
+    Ok(build_success_response(id, result))
+}
+
+/// Parse a JSON-RPC error response string into a structured object
+#[pyfunction]
+fn parse_error(input: &str) -> PyResult<PyJsonRpcError> {
+    let inner = parse_error_impl(input)?;
+    Ok(PyJsonRpcError { inner })
+}
+
+/// Parse a JSON-RPC response of either kind, returning a `JsonRpcResponse`
+/// (success) or a `JsonRpcError` (failure) depending on which member is present.
+#[pyfunction]
+fn parse_response_any(py: Python<'_>, input: &str) -> PyResult<PyObject> {
+    match parse_response_any_impl(input)? {
+        ResponseKind::Success(inner) => Ok(PyJsonRpcResponse { inner }.into_py(py)),
+        ResponseKind::Error(inner) => Ok(PyJsonRpcError { inner }.into_py(py)),
+    }
+}
+
+/// Parse a JSON-RPC message, returning a `JsonRpcRequest` or a
+/// `JsonRpcNotification` depending on whether an `id` is present.
+///
+/// Lets the gateway route notifications without emitting a reply.
+#[pyfunction]
+fn parse_message(py: Python<'_>, input: &str) -> PyResult<PyObject> {
+    match parse_message_impl(input)? {
+        Message::Request(inner) => Ok(PyJsonRpcRequest { inner }.into_py(py)),
+        Message::Notification(inner) => Ok(PyJsonRpcNotification { inner }.into_py(py)),
+    }
+}
+
+/// Parse a single buffer that may contain a JSON-RPC batch array.
+///
+/// Returns a list with one entry per batch element. Successful elements are
+/// `JsonRpcRequest` or `JsonRpcNotification` objects depending on whether an
+/// `id` is present; malformed elements are returned as the corresponding
+/// `ValueError` instance, so the gateway can build a partial batch reply
+/// without the whole buffer being rejected.
+#[pyfunction]
+fn parse_batch(py: Python<'_>, input: &str) -> PyResult<Vec<PyObject>> {
+    let results = parse_batch_impl(input)?;
+    Ok(results
+        .into_iter()
+        .map(|r| match r {
+            Ok(Message::Request(inner)) => PyJsonRpcRequest { inner }.into_py(py),
+            Ok(Message::Notification(inner)) => PyJsonRpcNotification { inner }.into_py(py),
+            Err(e) => PyErr::from(e).into_value(py).into_py(py),
+        })
+        .collect())
+}
+
+/// Python wrapper for the method-dispatch [`Router`].
+///
+/// Register Python callables per method, then feed raw message strings. A
+/// request returns its serialized reply; a notification (and any message with
+/// no reply) returns `None`.
+#[pyclass(name = "Router")]
+pub struct PyRouter {
+    inner: Router,
+}
+
+#[pymethods]
+impl PyRouter {
+    #[new]
+    fn new() -> Self {
+        PyRouter {
+            inner: Router::new(),
+        }
+    }
+
+    /// Register a handler callable for `method`.
+    ///
+    /// The callable receives the request params (a dict, list, or None) and its
+    /// return value becomes the response `result`. An exception is turned into
+    /// an internal-error response.
+    fn register(&mut self, method: &str, handler: PyObject) {
+        self.inner.register(method, move |params| {
+            Python::with_gil(|py| {
+                let args = params_to_py(py, params);
+                match handler.call1(py, (args,)) {
+                    Ok(ret) => py_to_json_value(py, &ret).map_err(|e| ErrorData {
+                        code: error_codes::INTERNAL_ERROR,
+                        message: e.to_string(),
+                        data: None,
+                    }),
+                    Err(e) => Err(ErrorData {
+                        code: error_codes::INTERNAL_ERROR,
+                        message: e.to_string(),
+                        data: None,
+                    }),
+                }
+            })
+        });
+    }
+
+    /// Handle a raw JSON-RPC message string, returning the serialized reply or
+    /// `None` for a notification. A malformed message yields a spec-compliant
+    /// error reply.
+    fn handle(&self, input: &str) -> Option<String> {
+        match parse_message_impl(input) {
+            Ok(message) => self.inner.dispatch_message(message).map(|k| serialize_response_kind(&k)),
+            Err(e) => Some(build_error_response(
+                RequestId::Null,
+                e.to_error_code(),
+                &e.to_string(),
+                None,
+            )),
+        }
+    }
+}
+
 /// Python module definition
 #[pymodule]
 fn mcp_parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -413,8 +1248,24 @@ fn mcp_parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse_response, m)?)?;
     m.add_function(wrap_pyfunction!(is_valid, m)?)?;
     m.add_function(wrap_pyfunction!(parse_requests_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_message, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_error, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_response_any, m)?)?;
+    m.add_function(wrap_pyfunction!(build_error_response_py, m)?)?;
+    m.add_function(wrap_pyfunction!(build_success_response_py, m)?)?;
+
+    // Standard JSON-RPC 2.0 error codes
+    m.add("PARSE_ERROR", error_codes::PARSE_ERROR)?;
+    m.add("INVALID_REQUEST", error_codes::INVALID_REQUEST)?;
+    m.add("METHOD_NOT_FOUND", error_codes::METHOD_NOT_FOUND)?;
+    m.add("INVALID_PARAMS", error_codes::INVALID_PARAMS)?;
+    m.add("INTERNAL_ERROR", error_codes::INTERNAL_ERROR)?;
     m.add_class::<PyJsonRpcRequest>()?;
     m.add_class::<PyJsonRpcResponse>()?;
+    m.add_class::<PyJsonRpcNotification>()?;
+    m.add_class::<PyJsonRpcError>()?;
+    m.add_class::<PyRouter>()?;
     Ok(())
 }
 
@@ -445,9 +1296,8 @@ mod tests {
         assert_eq!(req.id, RequestId::String("abc".to_string()));
         assert_eq!(req.method, "tools/call");
         
-        let params = req.params.unwrap();
         assert_eq!(
-            params.get("name").unwrap(),
+            req.params.get("name").unwrap(),
             &Value::String("get_posts".to_string())
         );
     }
@@ -526,9 +1376,8 @@ mod tests {
         }"#;
         
         let req = parse_request_impl(input).unwrap();
-        let params = req.params.unwrap();
-        let args = params.get("arguments").unwrap();
-        
+        let args = req.params.get("arguments").unwrap();
+
         assert!(args.is_object());
     }
 
@@ -536,7 +1385,390 @@ mod tests {
     fn test_null_id() {
         let input = r#"{"jsonrpc":"2.0","id":null,"method":"notify"}"#;
         let req = parse_request_impl(input).unwrap();
-        
+
         assert_eq!(req.id, RequestId::Null);
     }
+
+    #[test]
+    fn test_parse_batch_array() {
+        let input = r#"[
+            {"jsonrpc":"2.0","id":1,"method":"tools/list"},
+            {"jsonrpc":"2.0","id":2,"method":"initialize"}
+        ]"#;
+        let batch = parse_batch_impl(input).unwrap();
+
+        assert_eq!(batch.len(), 2);
+        match batch[0].as_ref().unwrap() {
+            Message::Request(req) => assert_eq!(req.id, RequestId::Number(1)),
+            Message::Notification(_) => panic!("expected request"),
+        }
+        match batch[1].as_ref().unwrap() {
+            Message::Request(req) => assert_eq!(req.method, "initialize"),
+            Message::Notification(_) => panic!("expected request"),
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_single_object() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+        let batch = parse_batch_impl(input).unwrap();
+
+        assert_eq!(batch.len(), 1);
+        match batch[0].as_ref().unwrap() {
+            Message::Request(req) => assert_eq!(req.method, "tools/list"),
+            Message::Notification(_) => panic!("expected request"),
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_partial_errors() {
+        let input = r#"[
+            {"jsonrpc":"2.0","id":1,"method":"tools/list"},
+            {"jsonrpc":"2.0","id":2}
+        ]"#;
+        let batch = parse_batch_impl(input).unwrap();
+
+        assert!(batch[0].is_ok());
+        assert!(matches!(
+            batch[1].as_ref().unwrap_err(),
+            ParseError::MissingField("method")
+        ));
+    }
+
+    #[test]
+    fn test_parse_batch_mixed_requests_and_notifications() {
+        let input = r#"[
+            {"jsonrpc":"2.0","id":1,"method":"tools/list"},
+            {"jsonrpc":"2.0","method":"notifications/cancelled"}
+        ]"#;
+        let batch = parse_batch_impl(input).unwrap();
+
+        assert_eq!(batch.len(), 2);
+        assert!(!batch[0].as_ref().unwrap().is_notification());
+        match batch[1].as_ref().unwrap() {
+            Message::Notification(n) => assert_eq!(n.method, "notifications/cancelled"),
+            Message::Request(_) => panic!("expected notification"),
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_empty_array() {
+        let err = parse_batch_impl("[]").unwrap_err();
+
+        assert!(matches!(err, ParseError::InvalidJson(_)));
+    }
+
+    #[test]
+    fn test_parse_batch_scalar_top_level() {
+        let err = parse_batch_impl("42").unwrap_err();
+
+        assert!(matches!(err, ParseError::InvalidJson(_)));
+    }
+
+    #[test]
+    fn test_parse_message_request() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+        let msg = parse_message_impl(input).unwrap();
+
+        assert!(!msg.is_notification());
+        match msg {
+            Message::Request(req) => assert_eq!(req.method, "tools/list"),
+            _ => panic!("expected request"),
+        }
+    }
+
+    #[test]
+    fn test_parse_message_notification() {
+        let input = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let msg = parse_message_impl(input).unwrap();
+
+        assert!(msg.is_notification());
+        match msg {
+            Message::Notification(n) => assert_eq!(n.method, "notifications/initialized"),
+            _ => panic!("expected notification"),
+        }
+    }
+
+    #[test]
+    fn test_parse_message_null_id_is_request() {
+        let input = r#"{"jsonrpc":"2.0","id":null,"method":"test"}"#;
+        let msg = parse_message_impl(input).unwrap();
+
+        assert!(!msg.is_notification());
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}"#;
+        let err = parse_error_impl(input).unwrap();
+
+        assert_eq!(err.id, RequestId::Number(1));
+        assert_eq!(err.error.code, -32601);
+        assert_eq!(err.error.message, "Method not found");
+        assert!(err.error.data.is_none());
+    }
+
+    #[test]
+    fn test_parse_error_response_with_data() {
+        let input =
+            r#"{"jsonrpc":"2.0","id":"x","error":{"code":-32602,"message":"bad","data":{"field":"id"}}}"#;
+        let err = parse_error_impl(input).unwrap();
+
+        assert!(err.error.data.unwrap().is_object());
+    }
+
+    #[test]
+    fn test_parse_error_response_code_out_of_i32_range() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"error":{"code":5000000000,"message":"bad"}}"#;
+        assert!(matches!(
+            parse_error_impl(input).unwrap_err(),
+            ParseError::InvalidFieldType("error.code", "i32")
+        ));
+    }
+
+    #[test]
+    fn test_parse_response_any_success() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"result":{"tools":[]}}"#;
+        match parse_response_any_impl(input).unwrap() {
+            ResponseKind::Success(resp) => assert!(resp.result.is_object()),
+            _ => panic!("expected success"),
+        }
+    }
+
+    #[test]
+    fn test_parse_response_any_error() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32603,"message":"oops"}}"#;
+        match parse_response_any_impl(input).unwrap() {
+            ResponseKind::Error(err) => assert_eq!(err.error.code, -32603),
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn test_parse_response_any_both_rejected() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"result":{},"error":{"code":-1,"message":"m"}}"#;
+        assert!(matches!(
+            parse_response_any_impl(input).unwrap_err(),
+            ParseError::InvalidFieldType("response", _)
+        ));
+    }
+
+    #[test]
+    fn test_parse_response_any_neither_rejected() {
+        let input = r#"{"jsonrpc":"2.0","id":1}"#;
+        assert!(matches!(
+            parse_response_any_impl(input).unwrap_err(),
+            ParseError::MissingField("result or error")
+        ));
+    }
+
+    #[test]
+    fn test_error_code_mapping() {
+        assert_eq!(
+            ParseError::InvalidJson("x".to_string()).to_error_code(),
+            error_codes::PARSE_ERROR
+        );
+        assert_eq!(
+            ParseError::MissingField("id").to_error_code(),
+            error_codes::INVALID_REQUEST
+        );
+        assert_eq!(
+            ParseError::InvalidFieldType("params", "object").to_error_code(),
+            error_codes::INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_build_success_response() {
+        let json = build_success_response(RequestId::Number(1), serde_json::json!({"ok": true}));
+        let resp = parse_response_impl(&json).unwrap();
+
+        assert_eq!(resp.id, RequestId::Number(1));
+        assert_eq!(resp.result, serde_json::json!({"ok": true}));
+    }
+
+    #[test]
+    fn test_build_error_response_roundtrip() {
+        let json = build_error_response(
+            RequestId::String("x".to_string()),
+            error_codes::METHOD_NOT_FOUND,
+            "Method not found",
+            None,
+        );
+        let err = parse_error_impl(&json).unwrap();
+
+        assert_eq!(err.error.code, -32601);
+        assert_eq!(err.error.message, "Method not found");
+        assert_eq!(err.id, RequestId::String("x".to_string()));
+    }
+
+    #[test]
+    fn test_parse_error_to_reply() {
+        let err = parse_request_impl("not json").unwrap_err();
+        let json = build_error_response(RequestId::Null, err.to_error_code(), &err.to_string(), None);
+        let reply = parse_error_impl(&json).unwrap();
+
+        assert_eq!(reply.error.code, error_codes::PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_parse_request_borrowed() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+        let req = parse_request_borrowed(input).unwrap();
+
+        assert_eq!(&*req.jsonrpc, "2.0");
+        assert_eq!(req.id, RequestId::Number(1));
+        assert_eq!(&*req.method, "tools/list");
+        assert!(req.params.is_none());
+        // Both strings borrow directly from the input buffer — the whole point
+        // of this function over `parse_request_impl`.
+        assert!(matches!(req.jsonrpc, Cow::Borrowed(_)));
+        assert!(matches!(req.method, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_parse_request_borrowed_missing_fields() {
+        assert!(matches!(
+            parse_request_borrowed(r#"{"id":1,"method":"tools/list"}"#).unwrap_err(),
+            ParseError::MissingField("jsonrpc")
+        ));
+        assert!(matches!(
+            parse_request_borrowed(r#"{"jsonrpc":"2.0","id":1}"#).unwrap_err(),
+            ParseError::MissingField("method")
+        ));
+        assert!(matches!(
+            parse_request_borrowed(r#"{"jsonrpc":"2.0","method":"tools/list"}"#).unwrap_err(),
+            ParseError::MissingField("id")
+        ));
+    }
+
+    #[test]
+    fn test_parse_request_borrowed_raw_params() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"x"}}"#;
+        let req = parse_request_borrowed(input).unwrap();
+
+        assert_eq!(req.params.unwrap().get(), r#"{"name":"x"}"#);
+    }
+
+    #[test]
+    fn test_borrowed_to_owned() {
+        let input = r#"{"jsonrpc":"2.0","id":"abc","method":"tools/call","params":{"name":"x"}}"#;
+        let owned = parse_request_borrowed(input).unwrap().to_owned().unwrap();
+
+        assert_eq!(owned.id, RequestId::String("abc".to_string()));
+        assert_eq!(
+            owned.params.get("name").unwrap(),
+            &Value::String("x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_request_borrowed_wrong_version() {
+        let input = r#"{"jsonrpc":"1.0","id":1,"method":"test"}"#;
+        assert!(matches!(
+            parse_request_borrowed(input).unwrap_err(),
+            ParseError::InvalidVersion(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_positional_params() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"sum","params":[1,2,3]}"#;
+        let req = parse_request_impl(input).unwrap();
+
+        assert_eq!(req.params.get_index(0).unwrap(), &Value::Number(1.into()));
+        assert_eq!(req.params.get_index(2).unwrap(), &Value::Number(3.into()));
+        assert!(req.params.get_index(3).is_none());
+        // By-name lookups return nothing for positional params.
+        assert!(req.params.get("x").is_none());
+    }
+
+    #[test]
+    fn test_named_params_have_no_index() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"f","params":{"a":1}}"#;
+        let req = parse_request_impl(input).unwrap();
+
+        assert!(req.params.get_index(0).is_none());
+        assert_eq!(req.params.get("a").unwrap(), &Value::Number(1.into()));
+    }
+
+    #[test]
+    fn test_reject_scalar_params() {
+        let input = r#"{"jsonrpc":"2.0","id":1,"method":"f","params":"oops"}"#;
+        assert!(matches!(
+            parse_request_impl(input).unwrap_err(),
+            ParseError::InvalidFieldType("params", _)
+        ));
+    }
+
+    #[test]
+    fn test_router_dispatch_success() {
+        let mut router = Router::new();
+        router.register("ping", |_params| Ok(serde_json::json!("pong")));
+
+        let req = parse_request_impl(r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#).unwrap();
+        match router.dispatch(req) {
+            ResponseKind::Success(resp) => {
+                assert_eq!(resp.result, serde_json::json!("pong"));
+                assert_eq!(resp.id, RequestId::Number(1));
+            }
+            _ => panic!("expected success"),
+        }
+    }
+
+    #[test]
+    fn test_router_unknown_method() {
+        let router = Router::new();
+        let req = parse_request_impl(r#"{"jsonrpc":"2.0","id":1,"method":"nope"}"#).unwrap();
+
+        match router.dispatch(req) {
+            ResponseKind::Error(err) => assert_eq!(err.error.code, error_codes::METHOD_NOT_FOUND),
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn test_router_handler_error() {
+        let mut router = Router::new();
+        router.register("fail", |_params| {
+            Err(ErrorData {
+                code: error_codes::INVALID_PARAMS,
+                message: "bad".to_string(),
+                data: None,
+            })
+        });
+
+        let req = parse_request_impl(r#"{"jsonrpc":"2.0","id":1,"method":"fail"}"#).unwrap();
+        match router.dispatch(req) {
+            ResponseKind::Error(err) => assert_eq!(err.error.code, error_codes::INVALID_PARAMS),
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn test_router_notification_no_reply() {
+        let mut router = Router::new();
+        router.register("notifications/initialized", |_params| Ok(Value::Null));
+
+        let msg =
+            parse_message_impl(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).unwrap();
+        assert!(router.dispatch_message(msg).is_none());
+    }
+
+    #[test]
+    fn test_parse_notification_with_params() {
+        let input =
+            r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":7}}"#;
+        let msg = parse_message_impl(input).unwrap();
+
+        match msg {
+            Message::Notification(n) => {
+                assert_eq!(
+                    n.params.get("requestId").unwrap(),
+                    &Value::Number(7.into())
+                );
+            }
+            _ => panic!("expected notification"),
+        }
+    }
 }